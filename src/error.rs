@@ -7,6 +7,9 @@ use std::fmt::Display;
 use std::fmt::Error as FmtError;
 use std::fmt::Formatter;
 use std::io::Error as IoError;
+use std::process::ExitStatus;
+
+use camino::Utf8PathBuf;
 
 /// A possible error value while loading a `Secret` from a [`SecretLoader`](crate::SecretLoader)
 ///
@@ -28,27 +31,75 @@ use std::io::Error as IoError;
 /// // Env var may be missing
 /// let env_cred = SecretLoader::new("env:MISSING_KEY");
 /// let env_error = SecretString::try_from(env_cred).expect_err("Env var is not set");
-/// assert!(matches!(env_error, LoadError::Env(_)));
+/// assert!(matches!(env_error, LoadError::Env(_, _)));
 ///
 /// // File may not available
 /// let file_cred = SecretLoader::new("file:/does/not/exist");
 /// let file_error = SecretString::try_from(file_cred).expect_err("File is missing");
-/// assert!(matches!(file_error, LoadError::Io(_)));
+/// assert!(matches!(file_error, LoadError::Io(_, _)));
 /// ```
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum LoadError {
-    /// An IO error was encountered while attempting to read from a file
-    Io(IoError),
-    /// A `VarError` was encountered while attempting to read from the environment
-    Env(VarError),
+    /// An IO error was encountered while attempting to read a secret from a file
+    ///
+    /// Carries the path that was being read, alongside the underlying [`IoError`].
+    Io(Utf8PathBuf, IoError),
+    /// A `VarError` was encountered while attempting to read a secret from the environment
+    ///
+    /// Carries the name of the variable that was being read, alongside the underlying
+    /// [`VarError`].
+    Env(String, VarError),
+    /// A `cmd:` helper process could not even be started
+    ///
+    /// Carries the command line that was being run, alongside the underlying [`IoError`].
+    CommandIo(Vec<String>, IoError),
+    /// A `cmd:` helper process exited with a non-zero status
+    ///
+    /// Carries the process's [`ExitStatus`] along with anything it wrote to stderr.
+    Command(ExitStatus, Vec<u8>),
+    /// Every source in a `SecretLoader::Chain` failed to resolve
+    ///
+    /// Carries the error produced by each attempted source, in the order they were tried.
+    Chain(Vec<LoadError>),
+    /// A file watcher set up by [`ReloadableSecret`](crate::ReloadableSecret) failed
+    ///
+    /// Carries the path that was being watched, alongside the underlying `notify::Error`.
+    #[cfg(feature = "watch")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "watch")))]
+    Watch(Utf8PathBuf, notify::Error),
+    /// A `base64:`/`hex:` modifier failed to decode the wrapped secret's resolved text
+    Decode(Box<dyn Error + Send + Sync>),
 }
 
 impl Display for LoadError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         match self {
-            Self::Io(_) => write!(f, "Io Error"),
-            Self::Env(_) => write!(f, "Env Error"),
+            Self::Io(path, e) => write!(f, "failed to read secret from file {path:?}: {e}"),
+            Self::Env(var, e) => {
+                write!(f, "failed to read secret from environment variable {var:?}: {e}")
+            }
+            Self::CommandIo(args, e) => {
+                write!(f, "failed to run secret command {:?}: {e}", args.join(" "))
+            }
+            Self::Command(status, stderr) => write!(
+                f,
+                "secret command exited with {status}: {}",
+                String::from_utf8_lossy(stderr)
+            ),
+            Self::Chain(errors) => {
+                write!(f, "all {} chained sources failed: [", errors.len())?;
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{error}")?;
+                }
+                write!(f, "]")
+            }
+            #[cfg(feature = "watch")]
+            Self::Watch(path, e) => write!(f, "failed to watch secret file {path:?}: {e}"),
+            Self::Decode(e) => write!(f, "failed to decode secret: {e}"),
         }
     }
 }
@@ -56,20 +107,14 @@ impl Display for LoadError {
 impl Error for LoadError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::Io(e) => Some(e),
-            Self::Env(e) => Some(e),
+            Self::Io(_, e) => Some(e),
+            Self::Env(_, e) => Some(e),
+            Self::CommandIo(_, e) => Some(e),
+            Self::Command(_, _) => None,
+            Self::Chain(_) => None,
+            #[cfg(feature = "watch")]
+            Self::Watch(_, e) => Some(e),
+            Self::Decode(e) => Some(e.as_ref()),
         }
     }
 }
-
-impl From<IoError> for LoadError {
-    fn from(e: IoError) -> Self {
-        Self::Io(e)
-    }
-}
-
-impl From<VarError> for LoadError {
-    fn from(e: VarError) -> Self {
-        Self::Env(e)
-    }
-}
@@ -131,8 +131,14 @@
 
 mod error;
 mod loader;
+mod registry;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "watch")]
+mod reload;
 
 pub use crate::error::LoadError;
 pub use crate::loader::SecretLoader;
+pub use crate::registry::SecretLoaderRegistry;
+#[cfg(feature = "watch")]
+pub use crate::reload::ReloadableSecret;
@@ -5,12 +5,19 @@ use std::convert::Infallible;
 use std::convert::TryFrom;
 use std::env;
 use std::fs;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::process::Command as ProcessCommand;
+use std::process::Stdio;
 use std::str::FromStr;
 
+use base64::Engine;
 use camino::Utf8PathBuf;
+use secrecy::ExposeSecret;
 use secrecy::Secret;
 
 use crate::error::LoadError;
+use crate::registry::SecretLoaderRegistry;
 
 /// A type that can load secrets from multiple locations
 ///
@@ -24,10 +31,53 @@ pub enum SecretLoader {
     Env(String),
     /// A secret that will be loaded from a file
     File(Utf8PathBuf),
+    /// A secret that will be loaded from the stdout of an external command
+    ///
+    /// The first element is the program to run and the rest are its arguments, already
+    /// tokenized (see [`SecretLoader::new`] for the `cmd:` syntax). The command's stdin is
+    /// inherited from the parent process, so a helper that needs to prompt on a TTY (e.g. to
+    /// unlock a keyring) still works. Note that a literal `|` anywhere in the hint is treated as
+    /// a [`SecretLoader::Chain`] separator, so it cannot currently appear in a command's
+    /// arguments.
+    Command(Vec<String>),
+    /// An ordered list of sources to try in turn, the first one to resolve successfully wins
+    ///
+    /// Parsed from a hint containing `|`, e.g. `env:DB_PASSWORD|file:/run/secrets/db`, but only
+    /// when at least one of the `|`-separated segments names a recognized scheme; a plaintext
+    /// secret that merely contains a literal `|` (a valid password/API-key character) is left as
+    /// [`SecretLoader::Plain`] unchanged.
+    Chain(Vec<SecretLoader>),
+    /// A secret that must be decoded after the wrapped loader resolves
+    ///
+    /// Parsed from a `base64:`/`hex:` prefix wrapping another hint, e.g.
+    /// `base64:file:/etc/keys/api.b64` or `hex:env:SIGNING_KEY`. Only meaningful when the secret
+    /// is resolved with [`SecretLoader::into_secret_bytes`]; [`SecretLoader::into_secret`] simply
+    /// returns the wrapped loader's text without decoding it.
+    Decode(Encoding, Box<SecretLoader>),
     /// A plaintext secret
     Plain(Secret<String>),
 }
 
+/// A text encoding that a [`SecretLoader::Decode`] hint can ask the inner secret to be decoded from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// The wrapped secret is standard (non-URL-safe) base64
+    Base64,
+    /// The wrapped secret is lowercase or uppercase hexadecimal
+    Hex,
+}
+
+impl Encoding {
+    fn decode(self, value: &str) -> Result<Vec<u8>, LoadError> {
+        match self {
+            Self::Base64 => base64::engine::general_purpose::STANDARD
+                .decode(value)
+                .map_err(|e| LoadError::Decode(Box::new(e))),
+            Self::Hex => hex::decode(value).map_err(|e| LoadError::Decode(Box::new(e))),
+        }
+    }
+}
+
 impl SecretLoader {
     /// Constructs a new `SecretLoader` from a provided str.
     ///
@@ -38,6 +88,9 @@ impl SecretLoader {
     ///
     /// let env_cred = SecretLoader::new("env:SECRET");
     /// let file_cred = SecretLoader::new("file:/some/file/path");
+    /// let cmd_cred = SecretLoader::new("cmd:vault read -field=value secret/db");
+    /// let chain_cred = SecretLoader::new("env:DB_PASSWORD|file:/run/secrets/db");
+    /// let base64_cred = SecretLoader::new("base64:file:/etc/keys/api.b64");
     /// let plain_cred = SecretLoader::new("plaintextpasswordsarebad");
     /// ```
     pub fn new<S: AsRef<str>>(val: S) -> Self {
@@ -49,13 +102,157 @@ impl SecretLoader {
     /// Use this method to actually 'load' or 'resolve' a usable `Secret`
     pub fn into_secret(self) -> Result<Secret<String>, LoadError> {
         let secret = match self {
-            Self::Env(env_var) => env::var(env_var)?.parse().expect("Infallible"),
-            Self::File(path) => fs::read_to_string(path)?.parse().expect("Infallible"),
+            Self::Env(env_var) => env::var(&env_var)
+                .map_err(|e| LoadError::Env(env_var, e))?
+                .parse()
+                .expect("Infallible"),
+            Self::File(path) => fs::read_to_string(&path)
+                .map_err(|e| LoadError::Io(path, e))?
+                .parse()
+                .expect("Infallible"),
+            Self::Command(args) => run_command(&args)?.parse().expect("Infallible"),
+            Self::Chain(links) => {
+                let mut errors = Vec::with_capacity(links.len());
+                for link in links {
+                    match link.into_secret() {
+                        Ok(secret) => return Ok(secret),
+                        Err(err) => errors.push(err),
+                    }
+                }
+                return Err(LoadError::Chain(errors));
+            }
+            Self::Decode(_, inner) => inner.into_secret()?,
+            Self::Plain(secret) => secret,
+        };
+        Ok(secret)
+    }
+
+    /// Converts a `SecretLoader` into a [`Secret<Vec<u8>>`](secrecy::Secret)
+    ///
+    /// A [`SecretLoader::Decode`] hint resolves its wrapped loader as text and then decodes that
+    /// text per its [`Encoding`]; anything else resolves through [`SecretLoader::into_secret`]
+    /// and is returned as the raw UTF-8 bytes of the resulting string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use secrecy::ExposeSecret;
+    /// use secret_loader::SecretLoader;
+    /// # use std::env;
+    /// # env::set_var("SIGNING_KEY", "68656c6c6f");
+    ///
+    /// let loader = SecretLoader::new("hex:env:SIGNING_KEY");
+    /// let secret = loader.into_secret_bytes().unwrap();
+    /// assert_eq!(secret.expose_secret(), b"hello");
+    /// ```
+    pub fn into_secret_bytes(self) -> Result<Secret<Vec<u8>>, LoadError> {
+        let bytes = match self {
+            Self::Decode(encoding, inner) => encoding.decode(inner.into_secret()?.expose_secret())?,
+            Self::Chain(links) => {
+                let mut errors = Vec::with_capacity(links.len());
+                for link in links {
+                    match link.into_secret_bytes() {
+                        Ok(secret) => return Ok(secret),
+                        Err(err) => errors.push(err),
+                    }
+                }
+                return Err(LoadError::Chain(errors));
+            }
+            other => other.into_secret()?.expose_secret().as_bytes().to_vec(),
+        };
+        Ok(Secret::new(bytes))
+    }
+
+    /// Converts a `SecretLoader` into a [`SecretString`](secrecy::SecretString), asynchronously
+    ///
+    /// Behaves like [`SecretLoader::into_secret`], but reads files with [`tokio::fs`] and runs
+    /// `cmd:` helpers via [`tokio::process`] so that resolving the secret does not block the
+    /// async runtime. Requires the `async` feature.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn into_secret_async(self) -> Result<Secret<String>, LoadError> {
+        let secret = match self {
+            Self::Env(env_var) => env::var(&env_var)
+                .map_err(|e| LoadError::Env(env_var, e))?
+                .parse()
+                .expect("Infallible"),
+            Self::File(path) => tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| LoadError::Io(path, e))?
+                .parse()
+                .expect("Infallible"),
+            Self::Command(args) => run_command_async(&args)
+                .await?
+                .parse()
+                .expect("Infallible"),
+            Self::Chain(links) => {
+                let mut errors = Vec::with_capacity(links.len());
+                for link in links {
+                    match Box::pin(link.into_secret_async()).await {
+                        Ok(secret) => return Ok(secret),
+                        Err(err) => errors.push(err),
+                    }
+                }
+                return Err(LoadError::Chain(errors));
+            }
+            Self::Decode(_, inner) => Box::pin(inner.into_secret_async()).await?,
             Self::Plain(secret) => secret,
         };
         Ok(secret)
     }
 
+    /// Converts a `SecretLoader` into a [`SecretString`](secrecy::SecretString) using a
+    /// [`SecretLoaderRegistry`]
+    ///
+    /// The `env` and `file` variants are resolved through the registry's `env`/`file` handlers
+    /// (so they can be overridden), and a `Plain` hint whose text starts with a registered
+    /// `scheme:` prefix is resolved through that scheme's handler instead of being returned
+    /// verbatim. `Command` is resolved the same way [`SecretLoader::into_secret`] does and is
+    /// *not* routed through the registry, so it cannot currently be intercepted or overridden by
+    /// a custom `cmd` handler. Anything else (including an unrecognized scheme) falls back to the
+    /// same behavior as [`SecretLoader::into_secret`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use secrecy::ExposeSecret;
+    /// use secrecy::Secret;
+    /// use secret_loader::SecretLoader;
+    /// use secret_loader::SecretLoaderRegistry;
+    ///
+    /// let mut registry = SecretLoaderRegistry::new();
+    /// registry.register("keyring", |key| Ok(Secret::new(format!("{key}-from-keyring"))));
+    ///
+    /// let loader = SecretLoader::new("keyring:db-password");
+    /// let secret = loader.resolve_with(&registry).unwrap();
+    /// assert_eq!(secret.expose_secret(), "db-password-from-keyring");
+    /// ```
+    pub fn resolve_with(self, registry: &SecretLoaderRegistry) -> Result<Secret<String>, LoadError> {
+        match self {
+            Self::Env(var) => registry.resolve("env", &var),
+            Self::File(path) => registry.resolve("file", path.as_str()),
+            Self::Command(args) => Ok(run_command(&args)?.parse().expect("Infallible")),
+            Self::Chain(links) => {
+                let mut errors = Vec::with_capacity(links.len());
+                for link in links {
+                    match link.resolve_with(registry) {
+                        Ok(secret) => return Ok(secret),
+                        Err(err) => errors.push(err),
+                    }
+                }
+                Err(LoadError::Chain(errors))
+            }
+            Self::Decode(_, inner) => inner.resolve_with(registry),
+            Self::Plain(secret) => match secret.expose_secret().split_once(':') {
+                Some((scheme, rest)) if registry.handlers.contains_key(scheme) => {
+                    let rest = rest.to_owned();
+                    registry.resolve(scheme, &rest)
+                }
+                _ => Ok(secret),
+            },
+        }
+    }
+
     /// Returns true if the secret will be loaded from an environment variable.
     ///
     /// ```
@@ -76,6 +273,36 @@ impl SecretLoader {
         matches!(self, Self::File(_))
     }
 
+    /// Returns true if the secret will be loaded from the stdout of an external command.
+    ///
+    /// ```
+    /// # use secret_loader::SecretLoader;
+    /// assert!(SecretLoader::new("cmd:gpg --decrypt secret.gpg").is_command());
+    /// ```
+    pub fn is_command(&self) -> bool {
+        matches!(self, Self::Command(_))
+    }
+
+    /// Returns true if the secret is an ordered chain of fallback sources.
+    ///
+    /// ```
+    /// # use secret_loader::SecretLoader;
+    /// assert!(SecretLoader::new("env:SECRET|file:/some/file/path").is_chain());
+    /// ```
+    pub fn is_chain(&self) -> bool {
+        matches!(self, Self::Chain(_))
+    }
+
+    /// Returns true if the secret is wrapped in a `base64:`/`hex:` decode modifier.
+    ///
+    /// ```
+    /// # use secret_loader::SecretLoader;
+    /// assert!(SecretLoader::new("base64:env:SECRET").is_decode());
+    /// ```
+    pub fn is_decode(&self) -> bool {
+        matches!(self, Self::Decode(_, _))
+    }
+
     /// Returns true if the secret is in plaintext.
     ///
     /// ```
@@ -91,15 +318,139 @@ impl FromStr for SecretLoader {
     type Err = Infallible;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // A bare `|` is only treated as a `Chain` separator if at least one of the resulting
+        // segments recognizably names a scheme; otherwise a plaintext secret that happens to
+        // contain a literal `|` (a perfectly valid password/API-key character) would be silently
+        // truncated to whatever precedes the first pipe instead of round-tripping unchanged.
+        if s.contains('|') && s.split('|').any(looks_like_scheme) {
+            let links = s
+                .split('|')
+                .map(str::parse)
+                .collect::<Result<Vec<_>, Infallible>>()?;
+            return Ok(Self::Chain(links));
+        }
+
         let cred = match s {
             val if val.starts_with("env:") => Self::Env(val[4..].to_owned()),
             val if val.starts_with("file:") => Self::File(val[5..].parse()?),
+            val if val.starts_with("cmd:") => Self::Command(split_command_line(&val[4..])),
+            val if val.starts_with("base64:") => {
+                Self::Decode(Encoding::Base64, Box::new(val[7..].parse()?))
+            }
+            val if val.starts_with("hex:") => Self::Decode(Encoding::Hex, Box::new(val[4..].parse()?)),
             val => Self::Plain(val.parse()?),
         };
         Ok(cred)
     }
 }
 
+/// Returns true if `segment` starts with one of the recognized scheme prefixes.
+///
+/// Used to decide whether a `|`-separated hint is actually a [`SecretLoader::Chain`], as opposed
+/// to a plaintext secret that merely contains a literal `|`.
+fn looks_like_scheme(segment: &str) -> bool {
+    segment.starts_with("env:")
+        || segment.starts_with("file:")
+        || segment.starts_with("cmd:")
+        || segment.starts_with("base64:")
+        || segment.starts_with("hex:")
+}
+
+/// Splits a `cmd:` hint into a program and its arguments.
+///
+/// This is a minimal, shell-free tokenizer in the spirit of `shlex`: it splits on whitespace
+/// but allows single- or double-quoted segments to contain whitespace. It does not perform any
+/// shell expansion (globbing, variable substitution, pipes, redirection, ...), so the resulting
+/// arguments are passed to the child process exactly as written.
+fn split_command_line(s: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut quote = None;
+
+    for c in s.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '\'' || c == '"' => {
+                quote = Some(c);
+                in_token = true;
+            }
+            None if c.is_whitespace() => {
+                if in_token {
+                    args.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_token = true;
+            }
+        }
+    }
+    if in_token {
+        args.push(current);
+    }
+
+    args
+}
+
+/// Runs a `cmd:` credential helper and returns its stdout with a single trailing newline
+/// trimmed, as UTF-8 (invalid sequences are replaced, matching [`String::from_utf8_lossy`]).
+///
+/// The helper's stdin is inherited from the parent process so interactive unlocking (e.g. a
+/// TTY prompt from `gpg` or a hardware token) still works.
+fn run_command(args: &[String]) -> Result<String, LoadError> {
+    let (program, rest) = args.split_first().ok_or_else(|| {
+        let io_err = IoError::new(ErrorKind::InvalidInput, "cmd: is missing a program name");
+        LoadError::CommandIo(args.to_vec(), io_err)
+    })?;
+
+    let output = ProcessCommand::new(program)
+        .args(rest)
+        .stdin(Stdio::inherit())
+        .output()
+        .map_err(|e| LoadError::CommandIo(args.to_vec(), e))?;
+
+    if !output.status.success() {
+        return Err(LoadError::Command(output.status, output.stderr));
+    }
+
+    let mut stdout = output.stdout;
+    if stdout.last() == Some(&b'\n') {
+        stdout.pop();
+    }
+
+    Ok(String::from_utf8_lossy(&stdout).into_owned())
+}
+
+/// Asynchronous counterpart of [`run_command`], used by [`SecretLoader::into_secret_async`].
+#[cfg(feature = "async")]
+async fn run_command_async(args: &[String]) -> Result<String, LoadError> {
+    let (program, rest) = args.split_first().ok_or_else(|| {
+        let io_err = IoError::new(ErrorKind::InvalidInput, "cmd: is missing a program name");
+        LoadError::CommandIo(args.to_vec(), io_err)
+    })?;
+
+    let output = tokio::process::Command::new(program)
+        .args(rest)
+        .stdin(Stdio::inherit())
+        .output()
+        .await
+        .map_err(|e| LoadError::CommandIo(args.to_vec(), e))?;
+
+    if !output.status.success() {
+        return Err(LoadError::Command(output.status, output.stderr));
+    }
+
+    let mut stdout = output.stdout;
+    if stdout.last() == Some(&b'\n') {
+        stdout.pop();
+    }
+
+    Ok(String::from_utf8_lossy(&stdout).into_owned())
+}
+
 impl From<String> for SecretLoader {
     fn from(s: String) -> Self {
         s.parse().expect("Infallible")
@@ -170,6 +521,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_command() {
+        let cred = "cmd:echo \"hello world\"".parse().unwrap();
+        match cred {
+            SecretLoader::Command(args) => {
+                assert_eq!(args, vec!["echo".to_owned(), "hello world".to_owned()]);
+            }
+            _ => panic!("Wrong loader type"),
+        }
+    }
+
+    #[test]
+    fn secret_from_command_present() {
+        let cred: SecretLoader = "cmd:echo supercommandsecret".parse().unwrap();
+        let secret: Secret<String> = cred.try_into().unwrap();
+
+        assert_eq!(secret.expose_secret(), "supercommandsecret");
+    }
+
+    #[test]
+    fn secret_from_command_failure() {
+        let cred: SecretLoader = "cmd:false".parse().unwrap();
+        let secret: Result<Secret<String>, _> = cred.try_into();
+
+        assert!(matches!(secret.unwrap_err(), LoadError::Command(_, _)));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    #[serial(Env)]
+    async fn secret_from_env_present_async() {
+        let cred: SecretLoader = "env:SECRET".parse().unwrap();
+
+        setup_env(Some("superenvsecret"));
+        assert!(env_is_set());
+
+        let secret = cred.into_secret_async().await.unwrap();
+        assert_eq!(secret.expose_secret(), "superenvsecret");
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn secret_from_file_present_async() {
+        let mut tempfile = NamedTempFile::new().unwrap();
+        write!(tempfile, "superfilesecret").unwrap();
+        let tempfile = tempfile.into_temp_path();
+
+        let cred: SecretLoader = format!("file:{}", tempfile.display()).parse().unwrap();
+        let secret = cred.into_secret_async().await.unwrap();
+
+        assert_eq!(secret.expose_secret(), "superfilesecret");
+        tempfile.close().unwrap();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn secret_from_command_present_async() {
+        let cred: SecretLoader = "cmd:echo supercommandsecret".parse().unwrap();
+        let secret = cred.into_secret_async().await.unwrap();
+
+        assert_eq!(secret.expose_secret(), "supercommandsecret");
+    }
+
     #[test]
     #[serial(Env)]
     fn secret_from_env_present() {
@@ -192,7 +606,10 @@ mod tests {
 
         let secret: Result<Secret<String>, _> = cred.try_into();
 
-        assert!(matches!(secret.unwrap_err(), LoadError::Env(_)));
+        match secret.unwrap_err() {
+            LoadError::Env(var, _) => assert_eq!(var, "SECRET"),
+            _ => panic!("Wrong error type"),
+        }
     }
 
     #[test]
@@ -214,7 +631,19 @@ mod tests {
 
         let secret: Result<Secret<String>, _> = cred.try_into();
 
-        assert!(matches!(secret.unwrap_err(), LoadError::Io(_)));
+        match secret.unwrap_err() {
+            LoadError::Io(path, _) => assert_eq!(path, "/does/not/exist"),
+            _ => panic!("Wrong error type"),
+        }
+    }
+
+    #[test]
+    fn file_missing_error_message_names_path() {
+        let cred: SecretLoader = "file:/does/not/exist".parse().unwrap();
+        let secret: Result<Secret<String>, _> = cred.try_into();
+
+        let message = secret.unwrap_err().to_string();
+        assert!(message.contains("/does/not/exist"), "{message}");
     }
 
     #[test]
@@ -224,4 +653,132 @@ mod tests {
 
         assert_eq!(secret.expose_secret(), "plaincredentialstorageisbad");
     }
+
+    #[test]
+    fn parse_chain() {
+        let cred = "env:SECRET|file:/home/user/.secrets|fallbacksecret"
+            .parse()
+            .unwrap();
+        match cred {
+            SecretLoader::Chain(links) => {
+                assert!(matches!(links.as_slice(), [
+                    SecretLoader::Env(_),
+                    SecretLoader::File(_),
+                    SecretLoader::Plain(_),
+                ]));
+            }
+            _ => panic!("Wrong loader type"),
+        }
+    }
+
+    #[test]
+    fn plain_secret_containing_pipe_round_trips_unchanged() {
+        let cred = "my|pipe|password".parse().unwrap();
+        match cred {
+            SecretLoader::Plain(secret) => {
+                assert_eq!(secret.expose_secret(), "my|pipe|password");
+            }
+            _ => panic!("Wrong loader type"),
+        }
+
+        let cred: SecretLoader = "my|pipe|password".parse().unwrap();
+        let secret: Secret<String> = cred.try_into().unwrap();
+        assert_eq!(secret.expose_secret(), "my|pipe|password");
+    }
+
+    #[test]
+    #[serial(Env)]
+    fn secret_from_chain_uses_first_success() {
+        let cred: SecretLoader = "env:SECRET|fallbacksecret".parse().unwrap();
+
+        setup_env(None);
+        assert!(!env_is_set());
+
+        let secret: Secret<String> = cred.try_into().unwrap();
+        assert_eq!(secret.expose_secret(), "fallbacksecret");
+    }
+
+    #[test]
+    #[serial(Env)]
+    fn secret_from_chain_all_fail() {
+        let cred: SecretLoader = "env:SECRET|file:/does/not/exist".parse().unwrap();
+
+        setup_env(None);
+        assert!(!env_is_set());
+
+        let secret: Result<Secret<String>, _> = cred.try_into();
+
+        match secret.unwrap_err() {
+            LoadError::Chain(errors) => assert_eq!(errors.len(), 2),
+            _ => panic!("Wrong error type"),
+        }
+    }
+
+    #[test]
+    fn parse_base64() {
+        let cred = "base64:env:SECRET".parse().unwrap();
+        match cred {
+            SecretLoader::Decode(Encoding::Base64, inner) => {
+                assert!(matches!(*inner, SecretLoader::Env(_)));
+            }
+            _ => panic!("Wrong loader type"),
+        }
+    }
+
+    #[test]
+    fn parse_hex() {
+        let cred = "hex:env:SECRET".parse().unwrap();
+        match cred {
+            SecretLoader::Decode(Encoding::Hex, inner) => {
+                assert!(matches!(*inner, SecretLoader::Env(_)));
+            }
+            _ => panic!("Wrong loader type"),
+        }
+    }
+
+    #[test]
+    fn secret_bytes_from_base64() {
+        let cred: SecretLoader = "base64:aGVsbG8gd29ybGQ=".parse().unwrap();
+        let secret = cred.into_secret_bytes().unwrap();
+
+        assert_eq!(secret.expose_secret(), b"hello world");
+    }
+
+    #[test]
+    fn secret_bytes_from_hex() {
+        let cred: SecretLoader = "hex:68656c6c6f".parse().unwrap();
+        let secret = cred.into_secret_bytes().unwrap();
+
+        assert_eq!(secret.expose_secret(), b"hello");
+    }
+
+    #[test]
+    fn secret_bytes_without_decode_modifier() {
+        let cred: SecretLoader = "plaincredentialstorageisbad".parse().unwrap();
+        let secret = cred.into_secret_bytes().unwrap();
+
+        assert_eq!(secret.expose_secret(), b"plaincredentialstorageisbad");
+    }
+
+    #[test]
+    fn secret_bytes_from_malformed_base64() {
+        let cred: SecretLoader = "base64:not valid base64!!".parse().unwrap();
+
+        match cred.into_secret_bytes() {
+            Err(LoadError::Decode(_)) => {}
+            _ => panic!("Expected a decode error"),
+        }
+    }
+
+    #[test]
+    #[serial(Env)]
+    fn secret_bytes_from_chain_decodes_winning_link() {
+        let cred: SecretLoader = "hex:env:MISSING|hex:env:SECRET".parse().unwrap();
+
+        setup_env(Some("68656c6c6f"));
+        assert!(env_is_set());
+
+        let secret = cred.into_secret_bytes().unwrap();
+        assert_eq!(secret.expose_secret(), b"hello");
+    }
 }
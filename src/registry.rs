@@ -0,0 +1,142 @@
+// Copyright (c) The secret-loader Contributors
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::fs;
+
+use camino::Utf8PathBuf;
+use secrecy::Secret;
+
+use crate::error::LoadError;
+
+type Handler = Box<dyn Fn(&str) -> Result<Secret<String>, LoadError> + Send + Sync>;
+
+/// A registry of `scheme:` handlers used to resolve a [`SecretLoader`](crate::SecretLoader) via
+/// [`SecretLoader::resolve_with`](crate::SecretLoader::resolve_with).
+///
+/// The built-in `env` and `file` schemes are registered by default, but can be overridden with
+/// [`SecretLoaderRegistry::register`]. Applications can also register their own schemes (e.g.
+/// `keyring`, `vault`, `sops`) without needing this crate to know about them; a hint whose scheme
+/// is not registered falls through and is treated as a plaintext secret.
+pub struct SecretLoaderRegistry {
+    pub(crate) handlers: HashMap<String, Handler>,
+}
+
+impl SecretLoaderRegistry {
+    /// Creates a new registry with the built-in `env` and `file` handlers registered.
+    pub fn new() -> Self {
+        let mut registry = Self {
+            handlers: HashMap::new(),
+        };
+        registry.register("env", |var| {
+            Ok(env::var(var)
+                .map_err(|e| LoadError::Env(var.to_owned(), e))?
+                .parse()
+                .expect("Infallible"))
+        });
+        registry.register("file", |path| {
+            Ok(fs::read_to_string(path)
+                .map_err(|e| LoadError::Io(Utf8PathBuf::from(path), e))?
+                .parse()
+                .expect("Infallible"))
+        });
+        registry
+    }
+
+    /// Registers a handler for `scheme`, replacing any handler already registered for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use secrecy::Secret;
+    /// use secret_loader::SecretLoaderRegistry;
+    ///
+    /// let mut registry = SecretLoaderRegistry::new();
+    /// registry.register("keyring", |key| Ok(Secret::new(format!("{key}-from-keyring"))));
+    /// ```
+    pub fn register<S, F>(&mut self, scheme: S, handler: F) -> &mut Self
+    where
+        S: Into<String>,
+        F: Fn(&str) -> Result<Secret<String>, LoadError> + Send + Sync + 'static,
+    {
+        self.handlers.insert(scheme.into(), Box::new(handler));
+        self
+    }
+
+    /// Runs the handler registered for `scheme`.
+    ///
+    /// Every call site either resolves `env`/`file` (always registered by [`Self::new`]) or has
+    /// already checked `self.handlers.contains_key(scheme)`, so there's always a handler here.
+    pub(crate) fn resolve(&self, scheme: &str, value: &str) -> Result<Secret<String>, LoadError> {
+        let handler = self
+            .handlers
+            .get(scheme)
+            .expect("resolve is only called for a registered scheme");
+        handler(value)
+    }
+}
+
+impl Default for SecretLoaderRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for SecretLoaderRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretLoaderRegistry")
+            .field("schemes", &self.handlers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use secrecy::ExposeSecret;
+
+    use super::*;
+    use crate::SecretLoader;
+
+    #[test]
+    fn unregistered_scheme_falls_through_to_plain() {
+        let registry = SecretLoaderRegistry::new();
+        let loader: SecretLoader = "keyring:my-secret".parse().unwrap();
+
+        let secret = loader.resolve_with(&registry).unwrap();
+        assert_eq!(secret.expose_secret(), "keyring:my-secret");
+    }
+
+    #[test]
+    fn custom_scheme_is_resolved() {
+        let mut registry = SecretLoaderRegistry::new();
+        registry.register("keyring", |key| Ok(Secret::new(format!("{key}-from-keyring"))));
+        let loader: SecretLoader = "keyring:my-secret".parse().unwrap();
+
+        let secret = loader.resolve_with(&registry).unwrap();
+        assert_eq!(secret.expose_secret(), "my-secret-from-keyring");
+    }
+
+    #[test]
+    fn builtin_handler_can_be_overridden() {
+        let mut registry = SecretLoaderRegistry::new();
+        registry.register("env", |_var| Ok(Secret::new("overridden".to_owned())));
+        let loader: SecretLoader = "env:SOME_VAR".parse().unwrap();
+
+        let secret = loader.resolve_with(&registry).unwrap();
+        assert_eq!(secret.expose_secret(), "overridden");
+    }
+
+    #[test]
+    fn command_is_not_routed_through_registry() {
+        // `cmd:` is documented as not going through the registry, so registering a "cmd" handler
+        // has no effect: resolve_with still runs the command itself.
+        let mut registry = SecretLoaderRegistry::new();
+        registry.register("cmd", |_args| Ok(Secret::new("intercepted".to_owned())));
+        let loader: SecretLoader = "cmd:echo supercommandsecret".parse().unwrap();
+
+        let secret = loader.resolve_with(&registry).unwrap();
+        assert_eq!(secret.expose_secret(), "supercommandsecret");
+    }
+}
@@ -0,0 +1,249 @@
+// Copyright (c) The secret-loader Contributors
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::fs;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use camino::Utf8Path;
+use camino::Utf8PathBuf;
+use notify::RecommendedWatcher;
+use notify::RecursiveMode;
+use notify::Watcher;
+use secrecy::Secret;
+
+use crate::error::LoadError;
+use crate::loader::SecretLoader;
+
+/// A secret that re-resolves itself when its backing source changes.
+///
+/// If `loader` is a [`SecretLoader::File`] — or resolves to one through a [`SecretLoader::Chain`]
+/// or [`SecretLoader::Decode`] wrapper, e.g. `env:DB_PASSWORD|file:/run/secrets/db` falling back
+/// to the file — this resolves the secret once, then watches the path and re-reads it whenever
+/// it's modified, so a rotated credential is picked up without restarting the process. Every
+/// other variant has nothing to watch, so the handle is simply resolved once and never changes.
+///
+/// The current value is read through [`ReloadableSecret::current`], which never blocks on the
+/// watcher thread. [`ReloadableSecret::changes`] optionally hands back a channel that receives a
+/// message every time the secret is reloaded.
+pub struct ReloadableSecret {
+    current: Arc<ArcSwap<Secret<String>>>,
+    changes: Option<mpsc::Receiver<()>>,
+    // Kept alive for as long as the `ReloadableSecret` is; dropping it stops the watch.
+    _watcher: Option<RecommendedWatcher>,
+}
+
+impl ReloadableSecret {
+    /// Resolves `loader` once, and starts watching its path if it is (or resolves to) a
+    /// [`SecretLoader::File`].
+    ///
+    /// A [`SecretLoader::Chain`] is resolved like [`SecretLoader::into_secret`] — the first link
+    /// that resolves successfully wins — and if that winning link is itself file-backed, its path
+    /// is watched. A [`SecretLoader::Decode`] wrapper is transparent to this: the file underneath
+    /// it is watched the same way, since decoding only matters for
+    /// [`SecretLoader::into_secret_bytes`].
+    pub fn new(loader: SecretLoader) -> Result<Self, LoadError> {
+        match loader {
+            SecretLoader::File(path) => Self::watching(path),
+            SecretLoader::Decode(_, inner) => Self::new(*inner),
+            SecretLoader::Chain(links) => {
+                let mut errors = Vec::with_capacity(links.len());
+                for link in links {
+                    match Self::new(link) {
+                        Ok(reloadable) => return Ok(reloadable),
+                        Err(err) => errors.push(err),
+                    }
+                }
+                Err(LoadError::Chain(errors))
+            }
+            other => {
+                let secret = other.into_secret()?;
+                Ok(Self {
+                    current: Arc::new(ArcSwap::from_pointee(secret)),
+                    changes: None,
+                    _watcher: None,
+                })
+            }
+        }
+    }
+
+    fn watching(path: Utf8PathBuf) -> Result<Self, LoadError> {
+        let secret = read_secret(&path)?;
+        let current = Arc::new(ArcSwap::from_pointee(secret));
+
+        let (tx, rx) = mpsc::channel();
+        let watched_path = path.clone();
+        let watched_current = Arc::clone(&current);
+        let file_name = path.file_name().map(str::to_owned);
+
+        // Watching the file's own path only survives until it is first replaced: an atomic
+        // "write a new file, then rename over the target" rotation (the pattern Kubernetes
+        // Secret/ConfigMap mounts and most credential rotators use) swaps the inode out from
+        // under the watch, silently ending it. Watching the parent directory instead and
+        // filtering events down to this file's name survives any number of rotations.
+        let parent = path
+            .parent()
+            .map(Utf8Path::to_path_buf)
+            .unwrap_or_else(|| Utf8PathBuf::from("."));
+
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let Ok(event) = event else {
+                return;
+            };
+            if event.kind.is_access() {
+                return;
+            }
+            let touches_file = event
+                .paths
+                .iter()
+                .any(|p| p.file_name() == file_name.as_deref().map(std::ffi::OsStr::new));
+            if !touches_file {
+                return;
+            }
+            if let Ok(secret) = read_secret(&watched_path) {
+                watched_current.store(Arc::new(secret));
+                let _ = tx.send(());
+            }
+        })
+        .map_err(|e| LoadError::Watch(path.clone(), e))?;
+        watcher
+            .watch(parent.as_std_path(), RecursiveMode::NonRecursive)
+            .map_err(|e| LoadError::Watch(path.clone(), e))?;
+
+        Ok(Self {
+            current,
+            changes: Some(rx),
+            _watcher: Some(watcher),
+        })
+    }
+
+    /// Returns the current value of the secret.
+    pub fn current(&self) -> Arc<Secret<String>> {
+        self.current.load_full()
+    }
+
+    /// Returns a channel that receives a message every time the secret is reloaded.
+    ///
+    /// Returns `None` for a `SecretLoader` that isn't watched (one that isn't, and doesn't
+    /// resolve to, a [`SecretLoader::File`]), since it can never change.
+    pub fn changes(&self) -> Option<&mpsc::Receiver<()>> {
+        self.changes.as_ref()
+    }
+}
+
+fn read_secret(path: &Utf8PathBuf) -> Result<Secret<String>, LoadError> {
+    Ok(fs::read_to_string(path)
+        .map_err(|e| LoadError::Io(path.clone(), e))?
+        .parse()
+        .expect("Infallible"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::time::Duration;
+
+    use secrecy::ExposeSecret;
+    use tempfile::NamedTempFile;
+
+    use super::*;
+
+    #[test]
+    fn static_for_plain() {
+        let loader = SecretLoader::new("plaintextpasswordsarebad");
+        let reloadable = ReloadableSecret::new(loader).unwrap();
+
+        assert_eq!(
+            reloadable.current().expose_secret(),
+            "plaintextpasswordsarebad"
+        );
+        assert!(reloadable.changes().is_none());
+    }
+
+    #[test]
+    fn watches_file_link_that_wins_a_chain() {
+        let mut tempfile = NamedTempFile::new().unwrap();
+        write!(tempfile, "original").unwrap();
+
+        // Mirrors chunk0-4's headline example (`env:DB_PASSWORD|file:/run/secrets/db`): the env
+        // var is missing, so the file link is the one that actually resolves, and its path
+        // should still end up watched.
+        let loader = SecretLoader::new(format!(
+            "env:SECRET_MISSING_FOR_RELOAD_CHAIN_TEST|file:{}",
+            tempfile.path().display()
+        ));
+        let reloadable = ReloadableSecret::new(loader).unwrap();
+        assert_eq!(reloadable.current().expose_secret(), "original");
+
+        let changes = reloadable.changes().unwrap();
+
+        fs::write(tempfile.path(), "rotated").unwrap();
+        wait_for(&reloadable, changes, "rotated");
+    }
+
+    #[test]
+    fn reloads_on_file_change() {
+        let mut tempfile = NamedTempFile::new().unwrap();
+        write!(tempfile, "original").unwrap();
+
+        let loader = SecretLoader::new(format!("file:{}", tempfile.path().display()));
+        let reloadable = ReloadableSecret::new(loader).unwrap();
+        assert_eq!(reloadable.current().expose_secret(), "original");
+
+        let changes = reloadable.changes().unwrap();
+
+        fs::write(tempfile.path(), "rotated").unwrap();
+
+        // A single write can surface as more than one filesystem event (e.g. truncate then
+        // write), so keep reloading until the final content shows up or we give up.
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while reloadable.current().expose_secret() != "rotated" {
+            assert!(std::time::Instant::now() < deadline, "secret never reloaded");
+            changes.recv_timeout(Duration::from_secs(5)).unwrap();
+        }
+    }
+
+    fn wait_for(reloadable: &ReloadableSecret, changes: &mpsc::Receiver<()>, expected: &str) {
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while reloadable.current().expose_secret() != expected {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "secret never reloaded to {expected:?}"
+            );
+            changes.recv_timeout(Duration::from_secs(5)).unwrap();
+        }
+    }
+
+    #[test]
+    fn reloads_across_repeated_rename_rotations() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secret");
+        fs::write(&path, "original").unwrap();
+
+        let loader = SecretLoader::new(format!("file:{}", path.display()));
+        let reloadable = ReloadableSecret::new(loader).unwrap();
+        assert_eq!(reloadable.current().expose_secret(), "original");
+
+        let changes = reloadable.changes().unwrap();
+
+        // Rotate the way Kubernetes Secret/ConfigMap mounts (and most credential rotators) do:
+        // write a new file alongside the target, then atomically rename it over the target.
+        let rotate_via_rename = |content: &str| {
+            let tmp = dir.path().join("secret.tmp");
+            fs::write(&tmp, content).unwrap();
+            fs::rename(&tmp, &path).unwrap();
+        };
+
+        rotate_via_rename("rotated-1");
+        wait_for(&reloadable, changes, "rotated-1");
+
+        // The watch must survive more than one rotation, not just the first.
+        rotate_via_rename("rotated-2");
+        wait_for(&reloadable, changes, "rotated-2");
+
+        // And a plain in-place write afterwards should still be picked up too.
+        fs::write(&path, "rotated-3").unwrap();
+        wait_for(&reloadable, changes, "rotated-3");
+    }
+}